@@ -0,0 +1,267 @@
+use serde::Deserialize;
+
+use crate::hint::Urgency;
+#[cfg(feature = "images")]
+use crate::image::Image;
+use crate::notification::Notification;
+use crate::timeout::Timeout;
+
+/// One entry of the `actions` array in a `NotifyRequest`.
+#[derive(Deserialize)]
+pub struct ActionRequest {
+    pub identifier: String,
+    pub label: String,
+}
+
+/// JSON-friendly mirror of `Timeout`.
+///
+/// Accepts either the tagged form (`{"type": "Milliseconds", "value": 6000}`) or a plain
+/// string understood by `Timeout::from_str` (`"default"`, `"never"`, or a bare millisecond
+/// count).
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum TimeoutRequest {
+    Tagged {
+        #[serde(rename = "type")]
+        kind: TimeoutKind,
+        value: Option<u32>,
+    },
+    Str(String),
+}
+
+#[derive(Deserialize)]
+pub enum TimeoutKind {
+    Default,
+    Never,
+    Milliseconds,
+}
+
+impl TryFrom<TimeoutRequest> for Timeout {
+    type Error = crate::timeout::ParseTimeoutError;
+
+    fn try_from(timeout: TimeoutRequest) -> Result<Self, Self::Error> {
+        match timeout {
+            TimeoutRequest::Tagged {
+                kind: TimeoutKind::Default,
+                ..
+            } => Ok(Timeout::Default),
+            TimeoutRequest::Tagged {
+                kind: TimeoutKind::Never,
+                ..
+            } => Ok(Timeout::Never),
+            TimeoutRequest::Tagged {
+                kind: TimeoutKind::Milliseconds,
+                value,
+            } => {
+                // Goes into the `i32` `expire_timeout` D-Bus argument, so reject anything that
+                // wouldn't fit rather than silently wrapping into a negative timeout.
+                let value = value.unwrap_or_default();
+                if value > i32::MAX as u32 {
+                    return Err(crate::timeout::ParseTimeoutError);
+                }
+                Ok(Timeout::Milliseconds(value))
+            }
+            TimeoutRequest::Str(s) => s.parse(),
+        }
+    }
+}
+
+/// A base64-encoded raw image, as carried by `NotifyRequest::image`.
+#[cfg(feature = "images")]
+#[derive(Deserialize)]
+pub struct ImageRequest {
+    pub width: i32,
+    pub height: i32,
+    pub bytes_per_pixel: i32,
+    pub data: String,
+}
+
+/// Error turning an `ImageRequest` into an `Image`.
+#[cfg(feature = "images")]
+#[derive(Debug)]
+pub enum ImageRequestError {
+    /// The base64-encoded `data` payload could not be decoded.
+    InvalidBase64(base64::DecodeError),
+    /// `width`, `height` or `bytes_per_pixel` was zero, negative, or too large to compute a
+    /// rowstride (`width * bytes_per_pixel`) without overflowing `i32`.
+    InvalidDimensions,
+}
+
+#[cfg(feature = "images")]
+impl std::fmt::Display for ImageRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageRequestError::InvalidBase64(error) => write!(f, "invalid image data: {error}"),
+            ImageRequestError::InvalidDimensions => {
+                write!(f, "invalid image dimensions")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "images")]
+impl std::error::Error for ImageRequestError {}
+
+#[cfg(feature = "images")]
+impl TryFrom<ImageRequest> for Image {
+    type Error = ImageRequestError;
+
+    fn try_from(request: ImageRequest) -> Result<Self, Self::Error> {
+        use base64::Engine;
+
+        if request.width <= 0 || request.height <= 0 || request.bytes_per_pixel <= 0 {
+            return Err(ImageRequestError::InvalidDimensions);
+        }
+        if request.width.checked_mul(request.bytes_per_pixel).is_none() {
+            return Err(ImageRequestError::InvalidDimensions);
+        }
+
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(request.data)
+            .map_err(ImageRequestError::InvalidBase64)?;
+        Ok(Image::new(
+            request.width,
+            request.height,
+            request.bytes_per_pixel,
+            data,
+        ))
+    }
+}
+
+#[cfg(all(test, feature = "images"))]
+mod image_request_tests {
+    use super::*;
+
+    fn request(width: i32, height: i32, bytes_per_pixel: i32) -> ImageRequest {
+        ImageRequest {
+            width,
+            height,
+            bytes_per_pixel,
+            data: String::new(),
+        }
+    }
+
+    #[test]
+    fn rejects_zero_and_negative_dimensions() {
+        assert!(matches!(
+            Image::try_from(request(0, 1, 3)),
+            Err(ImageRequestError::InvalidDimensions)
+        ));
+        assert!(matches!(
+            Image::try_from(request(1, -1, 3)),
+            Err(ImageRequestError::InvalidDimensions)
+        ));
+        assert!(matches!(
+            Image::try_from(request(1, 1, 0)),
+            Err(ImageRequestError::InvalidDimensions)
+        ));
+    }
+
+    #[test]
+    fn rejects_rowstride_overflow() {
+        assert!(matches!(
+            Image::try_from(request(2_000_000_000, 1, 3)),
+            Err(ImageRequestError::InvalidDimensions)
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_base64() {
+        let mut request = request(1, 1, 3);
+        request.data = "not base64!!".into();
+
+        assert!(matches!(
+            Image::try_from(request),
+            Err(ImageRequestError::InvalidBase64(_))
+        ));
+    }
+
+    #[test]
+    fn accepts_valid_request() {
+        let mut request = request(2, 1, 3);
+        request.data = "AAAAAAAAAAAAAAAAAA==".into();
+
+        assert!(Image::try_from(request).is_ok());
+    }
+}
+
+/// Body of `POST /api/notify`.
+#[derive(Deserialize)]
+pub struct NotifyRequest {
+    pub summary: String,
+    pub subtitle: Option<String>,
+    pub body: Option<String>,
+    pub icon: Option<String>,
+    pub sound_name: Option<String>,
+    pub appname: Option<String>,
+    pub timeout: Option<TimeoutRequest>,
+    pub urgency: Option<Urgency>,
+    pub actions: Option<Vec<ActionRequest>>,
+    #[cfg(feature = "images")]
+    pub image: Option<ImageRequest>,
+}
+
+/// Error turning a `NotifyRequest` into a `Notification`.
+#[derive(Debug)]
+pub enum NotifyRequestError {
+    /// The `timeout` field could not be parsed.
+    InvalidTimeout(crate::timeout::ParseTimeoutError),
+    /// The `image` payload was invalid.
+    #[cfg(feature = "images")]
+    InvalidImage(ImageRequestError),
+}
+
+impl std::fmt::Display for NotifyRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotifyRequestError::InvalidTimeout(error) => write!(f, "{error}"),
+            #[cfg(feature = "images")]
+            NotifyRequestError::InvalidImage(error) => write!(f, "invalid image: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for NotifyRequestError {}
+
+impl TryFrom<NotifyRequest> for Notification {
+    type Error = NotifyRequestError;
+
+    fn try_from(request: NotifyRequest) -> Result<Self, Self::Error> {
+        let mut notification = Notification::new();
+        notification.summary(&request.summary);
+
+        if let Some(subtitle) = &request.subtitle {
+            notification.subtitle(subtitle);
+        }
+        if let Some(body) = &request.body {
+            notification.body(body);
+        }
+        if let Some(icon) = &request.icon {
+            notification.icon(icon);
+        }
+        if let Some(sound_name) = &request.sound_name {
+            notification.sound_name(sound_name);
+        }
+        if let Some(appname) = &request.appname {
+            notification.appname(appname);
+        }
+        if let Some(timeout) = request.timeout {
+            let timeout: Timeout = timeout
+                .try_into()
+                .map_err(NotifyRequestError::InvalidTimeout)?;
+            notification.timeout(timeout);
+        }
+        if let Some(urgency) = request.urgency {
+            notification.urgency(urgency);
+        }
+        for action in request.actions.into_iter().flatten() {
+            notification.action(&action.identifier, &action.label);
+        }
+        #[cfg(feature = "images")]
+        if let Some(image) = request.image {
+            notification.image(image.try_into().map_err(NotifyRequestError::InvalidImage)?);
+        }
+
+        Ok(notification)
+    }
+}
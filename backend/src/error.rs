@@ -0,0 +1,29 @@
+// Copied from https://github.com/hoodie/notify-rust/blob/main/src/error.rs
+
+use std::fmt;
+
+/// Alias for `Result<T, Error>`.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors that can occur while sending or managing a notification.
+#[derive(Debug)]
+pub enum Error {
+    /// Something went wrong with the underlying D-Bus connection.
+    Dbus(zbus::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Dbus(error) => write!(f, "dbus error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<zbus::Error> for Error {
+    fn from(error: zbus::Error) -> Self {
+        Error::Dbus(error)
+    }
+}
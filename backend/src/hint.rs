@@ -0,0 +1,121 @@
+// Copied from https://github.com/hoodie/notify-rust/blob/main/src/hints.rs
+
+use serde::Deserialize;
+
+#[cfg(feature = "images")]
+use crate::image::Image;
+
+/// Urgency of a notification, as defined by the freedesktop notification spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Urgency {
+    /// Informational, non critical.
+    Low,
+    /// Default behavior, what you'd normally expect.
+    Normal,
+    /// Requires the user's attention, some servers keep these around until dismissed.
+    Critical,
+}
+
+impl From<Urgency> for u8 {
+    fn from(urgency: Urgency) -> Self {
+        match urgency {
+            Urgency::Low => 0,
+            Urgency::Normal => 1,
+            Urgency::Critical => 2,
+        }
+    }
+}
+
+/// A single hint to attach to a `Notification`.
+///
+/// Hints end up as entries of the `a{sv}` hints dictionary sent over D-Bus.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Hint {
+    /// How urgently the notification requires the user's attention.
+    Urgency(Urgency),
+    /// Freedesktop category, e.g. `"device"` or `"email.arrived"`.
+    Category(String),
+    /// Whether the notification should stick around until explicitly closed.
+    Resident(bool),
+    /// Whether the notification should not be shown in a notification log.
+    Transient(bool),
+    /// Name of a sound from the freedesktop sound theme to play on display.
+    SoundName(String),
+    /// Raw image data to display alongside the notification.
+    #[cfg(feature = "images")]
+    Image(Image),
+}
+
+// `Hint`s are compared and hashed by discriminant only, so a `HashSet<Hint>` holds at most one
+// value per hint *kind* — setting e.g. `Urgency` twice replaces the old value instead of keeping
+// both around for the D-Bus hints dictionary to pick between at random.
+impl PartialEq for Hint {
+    fn eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+impl Eq for Hint {}
+
+impl std::hash::Hash for Hint {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn same_kind_hints_are_equal_regardless_of_payload() {
+        assert_eq!(Hint::Urgency(Urgency::Low), Hint::Urgency(Urgency::Critical));
+        assert_eq!(
+            Hint::Category("a".into()),
+            Hint::Category("b".into())
+        );
+    }
+
+    #[test]
+    fn different_kind_hints_are_not_equal() {
+        assert_ne!(Hint::Urgency(Urgency::Low), Hint::Resident(true));
+    }
+
+    #[test]
+    fn replace_overwrites_same_kind_hint() {
+        let mut hints = HashSet::new();
+        hints.insert(Hint::Urgency(Urgency::Low));
+        hints.replace(Hint::Urgency(Urgency::Critical));
+
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints.get(&Hint::Urgency(Urgency::Low)), Some(&Hint::Urgency(Urgency::Critical)));
+    }
+
+    #[test]
+    fn plain_insert_does_not_overwrite_same_kind_hint() {
+        let mut hints = HashSet::new();
+        hints.insert(Hint::SoundName("a".into()));
+        // `insert` keeps the original value when an "equal" element is already present.
+        hints.insert(Hint::SoundName("b".into()));
+
+        assert_eq!(hints.len(), 1);
+        assert_eq!(
+            hints.get(&Hint::SoundName(String::new())),
+            Some(&Hint::SoundName("a".into()))
+        );
+    }
+
+    #[test]
+    fn distinct_kinds_accumulate() {
+        let mut hints = HashSet::new();
+        hints.insert(Hint::Urgency(Urgency::Normal));
+        hints.insert(Hint::Resident(true));
+        hints.insert(Hint::Transient(false));
+
+        assert_eq!(hints.len(), 3);
+    }
+}
@@ -0,0 +1,54 @@
+// Copied from https://github.com/hoodie/notify-rust/blob/main/src/image.rs
+
+/// Raw image data, to be attached to a notification as the `image-data` hint.
+///
+/// Only available behind the `images` feature.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Image {
+    width: i32,
+    height: i32,
+    bytes_per_pixel: i32,
+    data: Vec<u8>,
+}
+
+impl Image {
+    /// Builds an `Image` from raw pixel data.
+    ///
+    /// `bytes_per_pixel` is `3` for RGB data and `4` for RGBA data; anything else is rejected by
+    /// the notification server.
+    pub fn new(width: i32, height: i32, bytes_per_pixel: i32, data: Vec<u8>) -> Image {
+        Image {
+            width,
+            height,
+            bytes_per_pixel,
+            data,
+        }
+    }
+
+    pub(crate) fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub(crate) fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub(crate) fn rowstride(&self) -> i32 {
+        // Saturate instead of overflowing: callers that construct bogus dimensions directly
+        // (bypassing the JSON boundary's validation) get a clamped-but-safe value rather than a
+        // panic.
+        self.width.saturating_mul(self.bytes_per_pixel)
+    }
+
+    pub(crate) fn has_alpha(&self) -> bool {
+        self.bytes_per_pixel == 4
+    }
+
+    pub(crate) fn channels(&self) -> i32 {
+        self.bytes_per_pixel
+    }
+
+    pub(crate) fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
@@ -1,8 +1,16 @@
 use actix_web::{middleware::Logger, web, App, HttpResponse, HttpServer};
 use serde::{Deserialize, Serialize};
 
+mod api;
+mod error;
+mod hint;
+#[cfg(feature = "images")]
+mod image;
 mod timeout;
 mod notification;
+#[cfg(target_os = "linux")]
+mod xdg;
+use api::NotifyRequest;
 use notification::Notification;
 
 
@@ -11,6 +19,11 @@ struct Message {
     message: String,
 }
 
+#[derive(Serialize)]
+struct NotifyResponse {
+    id: u32,
+}
+
 async fn health() -> HttpResponse {
     HttpResponse::Ok().json(Message {
         message: "Backend is running!".to_string(),
@@ -24,15 +37,37 @@ async fn hello(name: web::Path<String>) -> HttpResponse {
 }
 
 async fn notify() -> HttpResponse {
-    Notification::new()
+    let result = Notification::new()
         .summary("Notificable Purr")
         .body("This is a test notification from the backend!")
         .show();
-        // .unwrap();
 
-    HttpResponse::Ok().json(Message {
-        message: "Notification sent!".to_string(),
-    })
+    match result {
+        Ok(handle) => HttpResponse::Ok().json(Message {
+            message: format!("Notification sent with id {}!", handle.id),
+        }),
+        Err(error) => HttpResponse::InternalServerError().json(Message {
+            message: error.to_string(),
+        }),
+    }
+}
+
+async fn notify_json(request: web::Json<NotifyRequest>) -> HttpResponse {
+    let notification: Notification = match request.into_inner().try_into() {
+        Ok(notification) => notification,
+        Err(error) => {
+            return HttpResponse::BadRequest().json(Message {
+                message: error.to_string(),
+            })
+        }
+    };
+
+    match notification.show() {
+        Ok(handle) => HttpResponse::Ok().json(NotifyResponse { id: handle.id }),
+        Err(error) => HttpResponse::InternalServerError().json(Message {
+            message: error.to_string(),
+        }),
+    }
 }
 
 #[actix_web::main]
@@ -49,7 +84,8 @@ async fn main() -> std::io::Result<()> {
                 web::scope("/api")
                     .route("/health", web::get().to(health))
                     .route("/hello/{name}", web::get().to(hello))
-                    .route("/notify", web::get().to(notify)),
+                    .route("/notify", web::get().to(notify))
+                    .route("/notify", web::post().to(notify_json)),
             )
     })
     .bind("127.0.0.1:3001")?
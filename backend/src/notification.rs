@@ -20,12 +20,26 @@
 /// 
 
 
+use std::collections::HashSet;
+
+use crate::hint::{Hint, Urgency};
+#[cfg(feature = "images")]
+use crate::image::Image;
 use crate::timeout::Timeout;
 
+#[cfg(target_os = "linux")]
+pub use crate::xdg::NotificationHandle;
+
 
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct Notification {
+    /// Name of the application, defaults to the name of the current executable.
+    ///
+    /// Used by some desktop environments to group notifications, and for correct notification
+    /// replacement on Plasma.
+    pub appname: String,
+
     /// Single line to summarize the content.
     pub summary: String,
 
@@ -41,6 +55,24 @@ pub struct Notification {
 
     /// Only to be used on the receive end. Use Notification hand for updating.
     pub(crate) id: Option<u32>,
+
+    /// Hints are a way to pass extra information to the server.
+    pub hints: HashSet<Hint>,
+
+    /// Actions are a way to ask the user to choose between different options.
+    ///
+    /// Stored as alternating `(identifier, label)` pairs, as expected by the D-Bus `actions`
+    /// argument.
+    pub actions: Vec<String>,
+
+    /// Icon of the notification, either a freedesktop icon-theme name or a `file://` URI.
+    pub icon: String,
+
+    /// Name of a sound from the freedesktop sound theme to play when the notification is shown.
+    ///
+    /// Only consumed on XDG, where it ends up as the `sound-name` hint. There is no `macos`
+    /// backend in this crate yet, so this field is otherwise inert.
+    pub sound_name: Option<String>,
 }
 
 impl Notification {
@@ -53,6 +85,15 @@ impl Notification {
         Notification::default()
     }
 
+    /// Set the `appname`.
+    ///
+    /// Overrides the default, which is the name of the current executable. Some desktop
+    /// environments (e.g. Plasma) use this to group and correctly replace notifications.
+    pub fn appname(&mut self, appname: &str) -> &mut Notification {
+        appname.clone_into(&mut self.appname);
+        self
+    }
+
     /// Set the `summary`.
     ///
     /// Often acts as title of the notification. For more elaborate content use the `body` field.
@@ -131,6 +172,72 @@ impl Notification {
         self
     }
 
+    /// Add a `Hint` to this notification.
+    ///
+    /// Hints end up in the `a{sv}` hints dictionary sent to the notification server, and can be
+    /// used for things like marking a notification resident or transient, or attaching a
+    /// category.
+    ///
+    /// (xdg only)
+    pub fn hint(&mut self, hint: Hint) -> &mut Notification {
+        // `replace` (not `insert`) so a later call for the same hint kind deterministically
+        // overwrites the earlier one, since `Hint` is compared/hashed by discriminant only.
+        self.hints.replace(hint);
+        self
+    }
+
+    /// Set the `urgency`.
+    ///
+    /// Convenience wrapper around `hint(Hint::Urgency(urgency))`, since urgency is the most
+    /// commonly used hint.
+    ///
+    /// (xdg only)
+    pub fn urgency(&mut self, urgency: Urgency) -> &mut Notification {
+        self.hint(Hint::Urgency(urgency))
+    }
+
+    /// Add an action.
+    ///
+    /// To wait for the user to invoke an action, use the `wait_for_action` method of the
+    /// `NotificationHandle` object returned by `show()`.
+    ///
+    /// (xdg only)
+    pub fn action(&mut self, identifier: &str, label: &str) -> &mut Notification {
+        self.actions.push(identifier.to_owned());
+        self.actions.push(label.to_owned());
+        self
+    }
+
+    /// Set the `icon`.
+    ///
+    /// Accepts either the name of an icon from the freedesktop icon theme (e.g. `"dialog-info"`)
+    /// or a `file://` URI pointing directly at an image.
+    ///
+    /// (xdg only)
+    pub fn icon(&mut self, icon: &str) -> &mut Notification {
+        icon.clone_into(&mut self.icon);
+        self
+    }
+
+    /// Set the `sound_name`.
+    ///
+    /// Maps to the `sound-name` hint on XDG Desktops. Stored on the `Notification` for a future
+    /// macOS backend to pick up, but there is no such backend in this crate yet.
+    ///
+    /// (xdg only)
+    pub fn sound_name(&mut self, sound_name: &str) -> &mut Notification {
+        self.sound_name = Some(sound_name.to_owned());
+        self.hint(Hint::SoundName(sound_name.to_owned()))
+    }
+
+    /// Attach raw image data to the notification, via the `image-data` hint.
+    ///
+    /// (xdg only, requires the `images` feature)
+    #[cfg(feature = "images")]
+    pub fn image(&mut self, image: Image) -> &mut Notification {
+        self.hint(Hint::Image(image))
+    }
+
     /// Finalizes a Notification.
     ///
     /// Part of the builder pattern, returns a complete copy of the built notification.
@@ -139,9 +246,13 @@ impl Notification {
     }
 
 
+    /// Sends Notification to the D-Bus notification server of the session.
+    ///
+    /// Returns a `NotificationHandle` that can be used to track, close or update the
+    /// notification once the server has accepted it.
     #[cfg(target_os = "linux")]
-    pub fn show(&self) -> Notification {
-        self.clone()
+    pub fn show(&self) -> crate::error::Result<NotificationHandle> {
+        crate::xdg::show_notification(self)
     }
 
     /// Sends Notification to `NSUserNotificationCenter`.
@@ -166,11 +277,24 @@ impl Notification {
 impl Default for Notification {
     fn default() -> Notification {
         Notification {
+            appname: exe_name(),
             summary: String::new(),
             subtitle: None,
             body: String::new(),
             timeout: Timeout::Default,
             id: None,
+            hints: HashSet::new(),
+            actions: Vec::new(),
+            icon: String::new(),
+            sound_name: None,
         }
     }
 }
+
+/// Name of the current executable, used as the default `appname`.
+fn exe_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|path| path.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+        .unwrap_or_default()
+}
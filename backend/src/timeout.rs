@@ -0,0 +1,134 @@
+// Copied from https://github.com/hoodie/notify-rust/blob/main/src/timeout.rs
+
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Describes the timeout of a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timeout {
+    /// Expire according to server default.
+    Default,
+    /// Do not expire, has to be closed by the user.
+    Never,
+    /// Expire after n milliseconds.
+    Milliseconds(u32),
+}
+
+impl Timeout {
+    /// Converts the timeout to the `i32` expected by the `expire_timeout` D-Bus argument.
+    pub(crate) fn into_i32(self) -> i32 {
+        match self {
+            Timeout::Default => -1,
+            Timeout::Never => 0,
+            Timeout::Milliseconds(ms) => ms as i32,
+        }
+    }
+}
+
+impl From<Duration> for Timeout {
+    /// A zero duration means the notification should never expire.
+    /// A duration whose millisecond count doesn't fit into the `i32` `expire_timeout` D-Bus
+    /// argument falls back to `Timeout::Default`, rather than silently wrapping.
+    fn from(duration: Duration) -> Self {
+        if duration.is_zero() {
+            return Timeout::Never;
+        }
+
+        match i32::try_from(duration.as_millis()) {
+            Ok(ms) => Timeout::Milliseconds(ms as u32),
+            Err(_) => Timeout::Default,
+        }
+    }
+}
+
+/// Error returned when parsing a `Timeout` from a string fails.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseTimeoutError;
+
+impl std::fmt::Display for ParseTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid timeout, expected \"default\", \"never\" or a number of milliseconds")
+    }
+}
+
+impl std::error::Error for ParseTimeoutError {}
+
+impl FromStr for Timeout {
+    type Err = ParseTimeoutError;
+
+    /// Parses `"default"`, `"never"`, or a bare integer (milliseconds).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Timeout::Default),
+            "never" => Ok(Timeout::Never),
+            // Parsed as `i32`, not `u32`: the value ends up in the `i32` `expire_timeout`
+            // D-Bus argument, so anything above `i32::MAX` must be rejected rather than wrap,
+            // and anything negative must be rejected rather than bit-reinterpreted as `u32`.
+            ms => ms
+                .parse::<i32>()
+                .ok()
+                .filter(|&ms| ms >= 0)
+                .map(|ms| Timeout::Milliseconds(ms as u32))
+                .ok_or(ParseTimeoutError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_zero_is_never() {
+        assert_eq!(Timeout::from(Duration::from_millis(0)), Timeout::Never);
+    }
+
+    #[test]
+    fn duration_in_range_is_milliseconds() {
+        assert_eq!(
+            Timeout::from(Duration::from_millis(2000)),
+            Timeout::Milliseconds(2000)
+        );
+    }
+
+    #[test]
+    fn duration_too_large_falls_back_to_default() {
+        assert_eq!(Timeout::from(Duration::from_millis(u64::MAX)), Timeout::Default);
+        assert_eq!(
+            Timeout::from(Duration::from_millis(i32::MAX as u64 + 1)),
+            Timeout::Default
+        );
+    }
+
+    #[test]
+    fn from_str_parses_keywords() {
+        assert_eq!("default".parse(), Ok(Timeout::Default));
+        assert_eq!("never".parse(), Ok(Timeout::Never));
+    }
+
+    #[test]
+    fn from_str_parses_milliseconds() {
+        assert_eq!("6000".parse(), Ok(Timeout::Milliseconds(6000)));
+    }
+
+    #[test]
+    fn from_str_rejects_negative_milliseconds() {
+        assert!("-50".parse::<Timeout>().is_err());
+    }
+
+    #[test]
+    fn from_str_rejects_out_of_range_milliseconds() {
+        assert!("3000000000".parse::<Timeout>().is_err());
+    }
+
+    #[test]
+    fn from_str_rejects_garbage() {
+        assert!("not a number".parse::<Timeout>().is_err());
+    }
+
+    #[test]
+    fn into_i32_keeps_largest_parsed_value_positive() {
+        let huge = "2147483647".parse::<Timeout>().unwrap();
+        assert_eq!(huge.into_i32(), i32::MAX);
+    }
+}
@@ -0,0 +1,182 @@
+// Copied from https://github.com/hoodie/notify-rust/blob/main/src/xdg/mod.rs
+
+use std::collections::HashMap;
+
+use zbus::blocking::Connection;
+use zbus::zvariant::Value;
+
+use crate::error::Result;
+use crate::hint::Hint;
+use crate::notification::Notification;
+
+const NOTIFICATION_NAMESPACE: &str = "org.freedesktop.Notifications";
+const NOTIFICATION_OBJECTPATH: &str = "/org/freedesktop/Notifications";
+
+/// A handle to a notification that has been shown to the user.
+///
+/// Holding on to this allows updating, closing, or listening for the server's response to the
+/// notification it represents.
+#[derive(Debug)]
+pub struct NotificationHandle {
+    pub(crate) id: u32,
+    pub(crate) connection: Connection,
+    pub(crate) notification: Notification,
+}
+
+pub(crate) fn show_notification(notification: &Notification) -> Result<NotificationHandle> {
+    let connection = Connection::session()?;
+    let id = send_notification(&connection, notification)?;
+
+    Ok(NotificationHandle {
+        id,
+        connection,
+        notification: notification.clone(),
+    })
+}
+
+fn send_notification(connection: &Connection, notification: &Notification) -> Result<u32> {
+    let actions: Vec<&str> = notification.actions.iter().map(String::as_str).collect();
+    let hints = hints_to_map(&notification.hints);
+
+    let reply = connection.call_method(
+        Some(NOTIFICATION_NAMESPACE),
+        NOTIFICATION_OBJECTPATH,
+        Some(NOTIFICATION_NAMESPACE),
+        "Notify",
+        &(
+            notification.appname.as_str(),
+            notification.id.unwrap_or(0),
+            notification.icon.as_str(),
+            notification.summary.as_str(),
+            notification.body.as_str(),
+            actions,
+            hints,
+            notification.timeout.into_i32(),
+        ),
+    )?;
+
+    Ok(reply.body().deserialize()?)
+}
+
+fn notification_proxy(connection: &Connection) -> Result<zbus::blocking::Proxy<'static>> {
+    Ok(zbus::blocking::Proxy::new(
+        connection,
+        NOTIFICATION_NAMESPACE,
+        NOTIFICATION_OBJECTPATH,
+        NOTIFICATION_NAMESPACE,
+    )?)
+}
+
+impl NotificationHandle {
+    /// Blocks until the user invokes an action on this notification, then calls `f` with the
+    /// identifier of the action that was invoked.
+    ///
+    /// (xdg only)
+    pub fn wait_for_action<F: FnOnce(&str)>(self, f: F) {
+        let Ok(proxy) = notification_proxy(&self.connection) else {
+            return;
+        };
+        let Ok(mut signals) = proxy.receive_signal("ActionInvoked") else {
+            return;
+        };
+
+        for signal in signals.by_ref() {
+            if let Ok((id, action_key)) = signal.body().deserialize::<(u32, String)>() {
+                if id == self.id {
+                    f(&action_key);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Blocks until the server reports that this notification was closed, then calls `f`.
+    ///
+    /// (xdg only)
+    pub fn on_close<F: FnOnce()>(self, f: F) {
+        let Ok(proxy) = notification_proxy(&self.connection) else {
+            return;
+        };
+        let Ok(mut signals) = proxy.receive_signal("NotificationClosed") else {
+            return;
+        };
+
+        for signal in signals.by_ref() {
+            if let Ok((id, _reason)) = signal.body().deserialize::<(u32, u32)>() {
+                if id == self.id {
+                    f();
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Closes this notification.
+    ///
+    /// (xdg only)
+    pub fn close(self) {
+        let _ = self.connection.call_method(
+            Some(NOTIFICATION_NAMESPACE),
+            NOTIFICATION_OBJECTPATH,
+            Some(NOTIFICATION_NAMESPACE),
+            "CloseNotification",
+            &(self.id,),
+        );
+    }
+
+    /// Re-sends this notification, replacing the one currently displayed.
+    ///
+    /// (xdg only)
+    pub fn update(&mut self) {
+        self.notification.id = Some(self.id);
+        if let Ok(id) = send_notification(&self.connection, &self.notification) {
+            self.id = id;
+        }
+    }
+}
+
+/// Turns the crate's `Hint` set into the `a{sv}` dictionary the spec expects.
+fn hints_to_map(hints: &std::collections::HashSet<Hint>) -> HashMap<&str, Value> {
+    let mut map = HashMap::with_capacity(hints.len());
+
+    for hint in hints {
+        match hint {
+            Hint::Urgency(urgency) => {
+                map.insert("urgency", Value::U8((*urgency).into()));
+            }
+            Hint::Category(category) => {
+                map.insert("category", Value::from(category.as_str()));
+            }
+            Hint::Resident(resident) => {
+                map.insert("resident", Value::Bool(*resident));
+            }
+            Hint::Transient(transient) => {
+                map.insert("transient", Value::Bool(*transient));
+            }
+            Hint::SoundName(sound_name) => {
+                map.insert("sound-name", Value::from(sound_name.as_str()));
+            }
+            #[cfg(feature = "images")]
+            Hint::Image(image) => {
+                map.insert("image-data", image_to_value(image));
+            }
+        }
+    }
+
+    map
+}
+
+/// Serializes an `Image` into the `(iiibiiay)` struct the spec expects for `image-data`.
+#[cfg(feature = "images")]
+fn image_to_value(image: &crate::image::Image) -> Value {
+    zbus::zvariant::StructureBuilder::new()
+        .add_field(image.width())
+        .add_field(image.height())
+        .add_field(image.rowstride())
+        .add_field(image.has_alpha())
+        .add_field(8i32)
+        .add_field(image.channels())
+        .add_field(image.data().to_vec())
+        .build()
+        .into()
+}